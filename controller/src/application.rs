@@ -0,0 +1,30 @@
+use cs2::ViewController;
+use utils_state::StateRegistry;
+
+use crate::{
+    enhancements::{Enhancement, RenderContext},
+    settings::AppSettings,
+};
+
+/// Invoked once per frame by the overlay to draw every active enhancement. Resolves the
+/// view controller and settings once here instead of leaving each enhancement to do it.
+pub fn render_enhancements(
+    enhancements: &[Box<dyn Enhancement>],
+    states: &StateRegistry,
+    ui: &imgui::Ui,
+    view_controller: &ViewController,
+    settings: &AppSettings,
+) -> anyhow::Result<()> {
+    let ctx = RenderContext {
+        states,
+        ui,
+        view_controller,
+        settings,
+    };
+
+    for enhancement in enhancements {
+        enhancement.render(&ctx)?;
+    }
+
+    Ok(())
+}
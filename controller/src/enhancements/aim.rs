@@ -0,0 +1,17 @@
+use super::{Enhancement, RenderContext};
+use crate::UpdateContext;
+
+/// Aim assist enhancement. Both `update` and `render` are no-ops: the view angle is not
+/// adjusted and no FOV circle is drawn.
+#[derive(Default)]
+pub struct AimAssist {}
+
+impl Enhancement for AimAssist {
+    fn update(&mut self, _ctx: &UpdateContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render(&self, _ctx: &RenderContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
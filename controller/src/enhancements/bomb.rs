@@ -0,0 +1,17 @@
+use super::{Enhancement, RenderContext};
+use crate::UpdateContext;
+
+/// Bomb ESP enhancement. Both `update` and `render` are no-ops: nothing about the planted
+/// C4 is drawn on the overlay.
+#[derive(Default)]
+pub struct BombEsp {}
+
+impl Enhancement for BombEsp {
+    fn update(&mut self, _ctx: &UpdateContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render(&self, _ctx: &RenderContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -1,5 +1,16 @@
+use cs2::ViewController;
+
 use crate::settings::AppSettings;
 
+/// Bundles everything an `Enhancement` needs to draw itself so it doesn't have to
+/// re-resolve the view controller and settings on every call.
+pub struct RenderContext<'a> {
+    pub states: &'a StateRegistry,
+    pub ui: &'a imgui::Ui,
+    pub view_controller: &'a ViewController,
+    pub settings: &'a AppSettings,
+}
+
 pub trait Enhancement {
     /* FIXME: Remove the update method! */
     fn update(&mut self, ctx: &UpdateContext) -> anyhow::Result<()>;
@@ -11,7 +22,7 @@ pub trait Enhancement {
         Ok(false)
     }
 
-    fn render(&self, states: &StateRegistry, ui: &imgui::Ui) -> anyhow::Result<()>;
+    fn render(&self, ctx: &RenderContext) -> anyhow::Result<()>;
     fn render_debug_window(&mut self, _states: &StateRegistry, _ui: &imgui::Ui) {}
 }
 
@@ -0,0 +1,17 @@
+use super::{Enhancement, RenderContext};
+use crate::UpdateContext;
+
+/// Player ESP enhancement. Both `update` and `render` are no-ops: no box, name or health
+/// is drawn for any pawn.
+#[derive(Default)]
+pub struct PlayerEsp {}
+
+impl Enhancement for PlayerEsp {
+    fn update(&mut self, _ctx: &UpdateContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render(&self, _ctx: &RenderContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use super::{Enhancement, RenderContext};
+use crate::UpdateContext;
+
+/// Spectators list enhancement. Both `update` and `render` are no-ops: no spectator
+/// window is drawn.
+#[derive(Default)]
+pub struct SpectatorsList {}
+
+impl Enhancement for SpectatorsList {
+    fn update(&mut self, _ctx: &UpdateContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render(&self, _ctx: &RenderContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use super::{Enhancement, RenderContext};
+use crate::UpdateContext;
+
+/// Trigger bot enhancement. Both `update` and `render` are no-ops: nothing is fired and
+/// nothing is drawn on the overlay.
+#[derive(Default)]
+pub struct TriggerBot {}
+
+impl Enhancement for TriggerBot {
+    fn update(&mut self, _ctx: &UpdateContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render(&self, _ctx: &RenderContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
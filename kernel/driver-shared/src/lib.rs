@@ -0,0 +1,10 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod requests;
+
+/// Maximum number of offsets a single pointer chain may contain. Shared between the
+/// kernel driver (which enforces it while walking a chain) and usermode callers (which
+/// size their request buffers against it).
+pub const IO_MAX_DEREF_COUNT: usize = 16;
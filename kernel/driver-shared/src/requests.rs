@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+
+use crate::IO_MAX_DEREF_COUNT;
+
+#[repr(C)]
+pub struct RequestRead {
+    pub process_id: u32,
+
+    pub offsets: [u64; IO_MAX_DEREF_COUNT],
+    pub offset_count: usize,
+
+    pub buffer: *mut u8,
+    pub count: usize,
+}
+
+pub enum ResponseRead {
+    Success,
+    UnknownProcess,
+    InvalidAddress {
+        resolved_offsets: [u64; IO_MAX_DEREF_COUNT],
+        resolved_offset_count: usize,
+    },
+}
+
+/// A single offset chain within a `RequestReadBatch`, laid out the same way as
+/// `RequestRead` minus the (shared) `process_id`.
+#[repr(C)]
+pub struct ReadEntry {
+    pub offsets: [u64; IO_MAX_DEREF_COUNT],
+    pub offset_count: usize,
+
+    pub buffer: *mut u8,
+    pub count: usize,
+}
+
+/// Resolves multiple independent offset chains for the same process in one driver call,
+/// so the kernel only has to look up the process and `attach_process_stack` once.
+#[repr(C)]
+pub struct RequestReadBatch {
+    pub process_id: u32,
+    pub entries: Vec<ReadEntry>,
+}
+
+pub enum ResponseReadBatch {
+    /// One status per `RequestReadBatch::entries`, in the same order. A failure on one
+    /// entry does not affect the others.
+    Success { entries: Vec<ResponseRead> },
+    UnknownProcess,
+}
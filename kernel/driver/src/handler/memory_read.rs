@@ -1,29 +1,41 @@
 use alloc::vec::Vec;
-use valthrun_driver_shared::{requests::{RequestRead, ResponseRead}, IO_MAX_DEREF_COUNT};
+use valthrun_driver_shared::{requests::{RequestRead, RequestReadBatch, ResponseRead, ResponseReadBatch}, IO_MAX_DEREF_COUNT};
 use winapi::km::wdm::PEPROCESS;
 
 use crate::{kdef::{PsLookupProcessByProcessId, ProbeForRead}, kapi::{attach_process_stack, self, NTStatusEx}};
 
-pub fn handler_read(req: &RequestRead, res: &mut ResponseRead) -> anyhow::Result<()> {
-    let mut process: PEPROCESS = core::ptr::null_mut();
-    if let Err(_status) = unsafe { PsLookupProcessByProcessId(req.process_id, &mut process) }.ok() {
-        *res = ResponseRead::UnknownProcess;
-        return Ok(());
-    }
-    
-    if req.offset_count > IO_MAX_DEREF_COUNT || req.offset_count > req.offsets.len() {
+/// Outcome of resolving a single offset chain, before it's been copied into the
+/// caller's (unattached) output buffer.
+enum ReadOutcome {
+    Success(Vec<u8>),
+    InvalidAddress {
+        resolved_offsets: [u64; IO_MAX_DEREF_COUNT],
+        resolved_offset_count: usize,
+    },
+}
+
+/// Resolves a single offset chain and reads the final value into a kernel-owned buffer.
+/// Must be called while the target process is attached via `attach_process_stack`.
+/// Deliberately does *not* touch the caller-supplied output buffer: that buffer lives
+/// in our own (unattached) address space, so it must only be written to once the attach
+/// guard has been dropped.
+fn resolve_read_entry(
+    offsets: &[u64],
+    offset_count: usize,
+    count: usize,
+) -> anyhow::Result<ReadOutcome> {
+    if offset_count > IO_MAX_DEREF_COUNT || offset_count > offsets.len() {
         anyhow::bail!("offset count is not valid")
     }
-    
-    let mut read_buffer = Vec::with_capacity(req.count);
-    read_buffer.resize(req.count, 0u8);
 
-    let local_offsets = Vec::from(&req.offsets[0..req.offset_count]);
+    let mut read_buffer = Vec::with_capacity(count);
+    read_buffer.resize(count, 0u8);
+
+    let local_offsets = Vec::from(&offsets[0..offset_count]);
     let mut target_address = unsafe { core::mem::transmute::<_, *const u8>(local_offsets[0]) };
     let mut resolved_offsets = [0u64; IO_MAX_DEREF_COUNT];
     let mut offset_index = 1usize;
 
-    let attach_guard = attach_process_stack(process);
     let read_result = kapi::try_seh(|| {
         while offset_index < local_offsets.len() {
             let deref_address = unsafe {
@@ -33,7 +45,7 @@ pub fn handler_read(req: &RequestRead, res: &mut ResponseRead) -> anyhow::Result
                     .cast::<*const u8>() // Target address is trated as ptr
                     .read() // dereference ptr
             };
-    
+
             resolved_offsets[offset_index - 1] = deref_address as u64;
             target_address = deref_address.wrapping_offset(local_offsets[offset_index] as isize); // add the next offset
             offset_index += 1;
@@ -46,17 +58,76 @@ pub fn handler_read(req: &RequestRead, res: &mut ResponseRead) -> anyhow::Result
         read_buffer.copy_from_slice(read_source);
     });
 
-    drop(attach_guard);
     if !read_result.is_ok() {
-        *res = ResponseRead::InvalidAddress { resolved_offsets, resolved_offset_count: offset_index - 1  };
+        return Ok(ReadOutcome::InvalidAddress { resolved_offsets, resolved_offset_count: offset_index - 1 });
+    }
+
+    Ok(ReadOutcome::Success(read_buffer))
+}
+
+/// Copies a resolved outcome into the caller's output buffer. Must only be called
+/// *after* the process attach guard has been dropped, as `buffer` lives in our own
+/// address space, not the attached target's.
+fn finish_read_entry(outcome: ReadOutcome, buffer: *mut u8, count: usize) -> ResponseRead {
+    match outcome {
+        ReadOutcome::Success(read_buffer) => {
+            let out_buffer = unsafe { core::slice::from_raw_parts_mut(buffer, count) };
+            out_buffer.copy_from_slice(read_buffer.as_slice());
+            ResponseRead::Success
+        }
+        ReadOutcome::InvalidAddress { resolved_offsets, resolved_offset_count } => {
+            ResponseRead::InvalidAddress { resolved_offsets, resolved_offset_count }
+        }
+    }
+}
+
+pub fn handler_read(req: &RequestRead, res: &mut ResponseRead) -> anyhow::Result<()> {
+    let mut process: PEPROCESS = core::ptr::null_mut();
+    if let Err(_status) = unsafe { PsLookupProcessByProcessId(req.process_id, &mut process) }.ok() {
+        *res = ResponseRead::UnknownProcess;
+        return Ok(());
+    }
+
+    let attach_guard = attach_process_stack(process);
+    let result = resolve_read_entry(&req.offsets, req.offset_count, req.count);
+    drop(attach_guard);
+
+    *res = finish_read_entry(result?, req.buffer, req.count);
+    Ok(())
+}
+
+/// Same as `handler_read` but resolves multiple independent offset chains for the same
+/// process while only looking up the process and attaching to it once. This avoids the
+/// per-chain syscall + `KeStackAttachProcess` overhead callers like the radar generator
+/// pay when resolving dozens of pointer chains every tick.
+pub fn handler_read_batch(req: &RequestReadBatch, res: &mut ResponseReadBatch) -> anyhow::Result<()> {
+    let mut process: PEPROCESS = core::ptr::null_mut();
+    if let Err(_status) = unsafe { PsLookupProcessByProcessId(req.process_id, &mut process) }.ok() {
+        *res = ResponseReadBatch::UnknownProcess;
         return Ok(());
     }
 
-    /* Copy result to output */
-    let out_buffer = unsafe {
-        core::slice::from_raw_parts_mut(req.buffer, req.count)
-    };
-    out_buffer.copy_from_slice(read_buffer.as_slice());
-    *res = ResponseRead::Success;
+    let attach_guard = attach_process_stack(process);
+    let outcomes: Vec<ReadOutcome> = req
+        .entries
+        .iter()
+        .map(|entry| {
+            resolve_read_entry(&entry.offsets, entry.offset_count, entry.count).unwrap_or(
+                ReadOutcome::InvalidAddress {
+                    resolved_offsets: [0u64; IO_MAX_DEREF_COUNT],
+                    resolved_offset_count: 0,
+                },
+            )
+        })
+        .collect();
+    drop(attach_guard);
+
+    let entries = outcomes
+        .into_iter()
+        .zip(req.entries.iter())
+        .map(|(outcome, entry)| finish_read_entry(outcome, entry.buffer, entry.count))
+        .collect();
+
+    *res = ResponseReadBatch::Success { entries };
     Ok(())
 }
\ No newline at end of file
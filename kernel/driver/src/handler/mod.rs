@@ -0,0 +1,32 @@
+mod memory_read;
+pub use memory_read::*;
+
+use valthrun_driver_shared::requests::{RequestRead, RequestReadBatch, ResponseRead, ResponseReadBatch};
+
+/// The decoded body of an incoming device-control request, keyed by IOCTL.
+pub enum DriverRequest<'a> {
+    Read(&'a RequestRead),
+    ReadBatch(&'a RequestReadBatch),
+}
+
+pub enum DriverResponse {
+    Read(ResponseRead),
+    ReadBatch(ResponseReadBatch),
+}
+
+/// Routes a decoded request to its handler. Called from the driver's device-control
+/// entry point once it has matched the IOCTL code and borrowed the request buffer.
+pub fn dispatch_request(request: DriverRequest) -> anyhow::Result<DriverResponse> {
+    Ok(match request {
+        DriverRequest::Read(req) => {
+            let mut res = ResponseRead::UnknownProcess;
+            handler_read(req, &mut res)?;
+            DriverResponse::Read(res)
+        }
+        DriverRequest::ReadBatch(req) => {
+            let mut res = ResponseReadBatch::UnknownProcess;
+            handler_read_batch(req, &mut res)?;
+            DriverResponse::ReadBatch(res)
+        }
+    })
+}
@@ -11,14 +11,24 @@ use cs2::{
 };
 use cs2_schema_generated::cs2::client::{
     CEntityIdentity,
+    C_CSPlayerPawn,
     C_PlantedC4,
     C_C4,
+    C_DecoyProjectile,
+    C_FlashbangProjectile,
+    C_HEGrenadeProjectile,
+    C_Inferno,
+    C_MolotovProjectile,
+    C_SmokeGrenadeProjectile,
 };
 use obfstr::obfstr;
 use radar_shared::{
     BombDefuser,
+    DefuseOutcome,
+    GrenadeKind,
     PlantedC4State,
     RadarC4,
+    RadarGrenade,
     RadarPlantedC4,
     RadarPlayerInfo,
     RadarState,
@@ -29,6 +39,15 @@ pub trait RadarGenerator: Send {
     fn generate_state(&mut self) -> anyhow::Result<RadarState>;
 }
 
+/// Below this margin (in seconds) a defuse that still beats the fuse is reported as
+/// `DefuseOutcome::Tight` rather than `DefuseOutcome::Success`.
+const DEFUSE_OUTCOME_TIGHT_MARGIN: f32 = 0.5;
+
+/* Approximate effect lifetimes, used to turn a spawn timestamp into a remaining-time
+ * value the same way `planted_c4_to_radar_state` turns `m_flC4Blow` into `time_detonation`. */
+const GRENADE_SMOKE_EFFECT_DURATION: f32 = 17.5;
+const GRENADE_INFERNO_EFFECT_DURATION: f32 = 7.0;
+
 fn planted_c4_to_radar_state(
     generator: &CS2RadarGenerator,
     planted_c4: &C_PlantedC4,
@@ -45,10 +64,12 @@ fn planted_c4_to_radar_state(
 
     let entities = generator.states.resolve::<EntitySystem>(())?;
     let time_total = planted_c4.m_flTimerLength()?;
+    let time_detonation = time_fuse - globals.time_2()?;
 
     let defuser = if planted_c4.m_bBeingDefused()? {
         let time_defuse = planted_c4.m_flDefuseCountDown()?.m_Value()?;
         let time_total = planted_c4.m_flDefuseLength()?;
+        let time_remaining = time_defuse - globals.time_2()?;
 
         let handle_defuser = planted_c4.m_hBombDefuser()?;
         let defuser = entities
@@ -70,23 +91,122 @@ fn planted_c4_to_radar_state(
             .unwrap_or("Name Error".into())
             .to_string();
 
+        /* positive margin: defuse finishes this many seconds before the fuse would */
+        let margin = time_detonation - time_remaining;
+        let outcome = if margin < 0.0 {
+            DefuseOutcome::Fail { margin }
+        } else if margin < DEFUSE_OUTCOME_TIGHT_MARGIN {
+            DefuseOutcome::Tight { margin }
+        } else {
+            DefuseOutcome::Success { margin }
+        };
+
         Some(BombDefuser {
-            time_remaining: time_defuse - globals.time_2()?,
-            time_total: time_total,
+            time_remaining,
+            time_total,
 
             player_name: defuser_name,
+            defuse_outcome: outcome,
         })
     } else {
         None
     };
 
     Ok(PlantedC4State::Active {
-        time_detonation: time_fuse - globals.time_2()?,
+        time_detonation,
         time_total,
         defuser,
     })
 }
 
+fn grenade_to_radar_state(
+    generator: &CS2RadarGenerator,
+    entity_identity: &CEntityIdentity,
+    kind: GrenadeKind,
+) -> anyhow::Result<RadarGrenade> {
+    let globals = generator.states.resolve::<Globals>(())?;
+    let entity_id = entity_identity.handle::<()>()?.get_entity_index();
+
+    let (position, owner, effect_time, fire_positions) = match kind {
+        GrenadeKind::Smoke => {
+            let grenade = entity_identity
+                .entity_ptr::<C_SmokeGrenadeProjectile>()?
+                .read_schema()?;
+            let position = grenade.m_pGameSceneNode()?.read_schema()?.m_vecAbsOrigin()?;
+
+            /* Remaining seconds until the smoke clears, not "now" - recomputed every tick
+             * just like `time_detonation`, so it keeps counting down as ticks pass. */
+            let effect_time = if grenade.m_bDidSmokeEffect()? {
+                let expiry = grenade.m_flSpawnTime()? + GRENADE_SMOKE_EFFECT_DURATION;
+                Some(expiry - globals.time_2()?)
+            } else {
+                None
+            };
+
+            (position, grenade.m_hOwnerEntity()?, effect_time, Vec::new())
+        }
+        GrenadeKind::Molotov => {
+            let grenade = entity_identity
+                .entity_ptr::<C_MolotovProjectile>()?
+                .read_schema()?;
+            let position = grenade.m_pGameSceneNode()?.read_schema()?.m_vecAbsOrigin()?;
+
+            (position, grenade.m_hOwnerEntity()?, None, Vec::new())
+        }
+        GrenadeKind::Flashbang => {
+            let grenade = entity_identity
+                .entity_ptr::<C_FlashbangProjectile>()?
+                .read_schema()?;
+            let position = grenade.m_pGameSceneNode()?.read_schema()?.m_vecAbsOrigin()?;
+
+            (position, grenade.m_hOwnerEntity()?, None, Vec::new())
+        }
+        GrenadeKind::HeGrenade => {
+            let grenade = entity_identity
+                .entity_ptr::<C_HEGrenadeProjectile>()?
+                .read_schema()?;
+            let position = grenade.m_pGameSceneNode()?.read_schema()?.m_vecAbsOrigin()?;
+
+            (position, grenade.m_hOwnerEntity()?, None, Vec::new())
+        }
+        GrenadeKind::Decoy => {
+            let grenade = entity_identity
+                .entity_ptr::<C_DecoyProjectile>()?
+                .read_schema()?;
+            let position = grenade.m_pGameSceneNode()?.read_schema()?.m_vecAbsOrigin()?;
+
+            (position, grenade.m_hOwnerEntity()?, None, Vec::new())
+        }
+        GrenadeKind::Inferno => {
+            let inferno = entity_identity.entity_ptr::<C_Inferno>()?.read_schema()?;
+            let position = inferno.m_pGameSceneNode()?.read_schema()?.m_vecAbsOrigin()?;
+
+            let fire_positions_raw = inferno.m_firePositions()?;
+            let fire_position_count = (inferno.m_firePositionCount()? as usize).min(fire_positions_raw.len());
+            let fire_positions = fire_positions_raw[..fire_position_count].to_vec();
+
+            /* Remaining seconds until the fire burns out, same reasoning as the smoke case. */
+            let expiry = inferno.m_flSpawnTime()? + GRENADE_INFERNO_EFFECT_DURATION;
+            let effect_time = Some(expiry - globals.time_2()?);
+
+            (position, inferno.m_hOwnerEntity()?, effect_time, fire_positions)
+        }
+    };
+
+    Ok(RadarGrenade {
+        entity_id,
+        kind,
+        position,
+        thrower_entity_id: if owner.is_valid() {
+            Some(owner.get_entity_index())
+        } else {
+            None
+        },
+        effect_time,
+        fire_positions,
+    })
+}
+
 pub struct CS2RadarGenerator {
     states: StateRegistry,
 }
@@ -105,21 +225,31 @@ impl CS2RadarGenerator {
             .resolve::<PlayerPawnState>(player_pawn.handle::<()>()?.get_entity_index())?;
 
         match &*player_info {
-            PlayerPawnState::Alive(info) => Ok(Some(RadarPlayerInfo {
-                controller_entity_id: info.controller_entity_id,
-                pawn_entity_id: info.pawn_entity_id,
+            PlayerPawnState::Alive(info) => {
+                /* Kept separate from the cached PlayerPawnState so the web radar can
+                 * dead-reckon between ticks instead of us smoothing it server-side. */
+                let velocity = player_pawn
+                    .entity_ptr::<C_CSPlayerPawn>()?
+                    .read_schema()?
+                    .m_vecVelocity()?;
+
+                Ok(Some(RadarPlayerInfo {
+                    controller_entity_id: info.controller_entity_id,
+                    pawn_entity_id: info.pawn_entity_id,
 
-                player_name: info.player_name.clone(),
-                player_flashtime: info.player_flashtime,
-                player_has_defuser: info.player_has_defuser,
-                player_health: info.player_health,
+                    player_name: info.player_name.clone(),
+                    player_flashtime: info.player_flashtime,
+                    player_has_defuser: info.player_has_defuser,
+                    player_health: info.player_health,
 
-                position: [info.position.x, info.position.y, info.position.z],
-                rotation: info.rotation,
+                    position: [info.position.x, info.position.y, info.position.z],
+                    rotation: info.rotation,
+                    velocity: Some([velocity.x, velocity.y, velocity.z]),
 
-                team_id: info.team_id,
-                weapon: info.weapon.id(),
-            })),
+                    team_id: info.team_id,
+                    weapon: info.weapon.id(),
+                }))
+            }
             _ => Ok(None),
         }
     }
@@ -130,7 +260,10 @@ impl RadarGenerator for CS2RadarGenerator {
         self.states.invalidate_states();
 
         let current_map = self.states.resolve::<StateCurrentMap>(())?;
+        let globals = self.states.resolve::<Globals>(())?;
         let mut radar_state = RadarState {
+            state_time: globals.time_2()?,
+
             players: Vec::with_capacity(16),
             world_name: current_map
                 .current_map
@@ -141,6 +274,7 @@ impl RadarGenerator for CS2RadarGenerator {
 
             planted_c4: None,
             c4_entities: Default::default(),
+            grenades: Default::default(),
         };
 
         let entities = self.states.resolve::<EntitySystem>(())?;
@@ -213,6 +347,40 @@ impl RadarGenerator for CS2RadarGenerator {
                         },
                     });
                 }
+                "C_SmokeGrenadeProjectile" => {
+                    match grenade_to_radar_state(self, entity_identity, GrenadeKind::Smoke) {
+                        Ok(grenade) => radar_state.grenades.push(grenade),
+                        Err(error) => log::warn!("Failed to generate smoke grenade state: {:#}", error),
+                    }
+                }
+                "C_MolotovProjectile" => {
+                    match grenade_to_radar_state(self, entity_identity, GrenadeKind::Molotov) {
+                        Ok(grenade) => radar_state.grenades.push(grenade),
+                        Err(error) => log::warn!("Failed to generate molotov grenade state: {:#}", error),
+                    }
+                }
+                "C_FlashbangProjectile" => {
+                    match grenade_to_radar_state(self, entity_identity, GrenadeKind::Flashbang) {
+                        Ok(grenade) => radar_state.grenades.push(grenade),
+                        Err(error) => log::warn!("Failed to generate flashbang grenade state: {:#}", error),
+                    }
+                }
+                "C_HEGrenadeProjectile" => {
+                    match grenade_to_radar_state(self, entity_identity, GrenadeKind::HeGrenade) {
+                        Ok(grenade) => radar_state.grenades.push(grenade),
+                        Err(error) => log::warn!("Failed to generate HE grenade state: {:#}", error),
+                    }
+                }
+                "C_DecoyProjectile" => {
+                    match grenade_to_radar_state(self, entity_identity, GrenadeKind::Decoy) {
+                        Ok(grenade) => radar_state.grenades.push(grenade),
+                        Err(error) => log::warn!("Failed to generate decoy grenade state: {:#}", error),
+                    }
+                }
+                "C_Inferno" => match grenade_to_radar_state(self, entity_identity, GrenadeKind::Inferno) {
+                    Ok(grenade) => radar_state.grenades.push(grenade),
+                    Err(error) => log::warn!("Failed to generate inferno state: {:#}", error),
+                },
                 _ => {}
             }
         }
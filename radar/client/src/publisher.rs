@@ -0,0 +1,102 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rand::distributions::{Alphanumeric, DistString};
+use radar_shared::RadarState;
+use slotmap::{new_key_type, SlotMap};
+
+use crate::generator::RadarGenerator;
+
+new_key_type! {
+    struct ClientId;
+}
+
+struct Session {
+    token: String,
+    last_seen: Instant,
+}
+
+/// Drives a `RadarGenerator` on a fixed tick and fans the resulting `RadarState` out to
+/// any number of token-authenticated remote viewers. The generator only runs while at
+/// least one viewer is connected.
+pub struct RadarPublisher<G> {
+    generator: Mutex<G>,
+    tick_interval: Duration,
+    inactivity_timeout: Duration,
+
+    sessions: Mutex<SlotMap<ClientId, Session>>,
+    current_state: Mutex<Option<RadarState>>,
+}
+
+impl<G: RadarGenerator> RadarPublisher<G> {
+    pub fn new(generator: G, tick_interval: Duration, inactivity_timeout: Duration) -> Self {
+        Self {
+            generator: Mutex::new(generator),
+            tick_interval,
+            inactivity_timeout,
+
+            sessions: Mutex::new(SlotMap::with_key()),
+            current_state: Mutex::new(None),
+        }
+    }
+
+    /// Registers a new viewer session and returns its opaque session token.
+    pub fn join(&self) -> String {
+        let token = Alphanumeric.sample_string(&mut rand::thread_rng(), 32);
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.insert(Session {
+            token: token.clone(),
+            last_seen: Instant::now(),
+        });
+
+        token
+    }
+
+    pub fn leave(&self, token: &str) {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, session| session.token != token);
+    }
+
+    /// Returns the latest known `RadarState` for `token` and marks the session as seen,
+    /// or `None` if the token is unknown (e.g. it got evicted for inactivity). Every
+    /// state returned is a full snapshot, since the generator never produces deltas.
+    /// Until `run` has generated a first state this also returns `None`, even for a
+    /// known token; a session joining after all others left gets the last stored
+    /// snapshot until the next tick replaces it.
+    pub fn poll(&self, token: &str) -> Option<RadarState> {
+        {
+            let mut sessions = self.sessions.lock().unwrap();
+            let session = sessions.values_mut().find(|session| session.token == token)?;
+            session.last_seen = Instant::now();
+        }
+
+        self.current_state.lock().unwrap().clone()
+    }
+
+    /// Evicts sessions which haven't polled within `inactivity_timeout` and returns
+    /// whether any session is still connected.
+    fn evict_stale_sessions(&self) -> bool {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, session| session.last_seen.elapsed() < self.inactivity_timeout);
+        !sessions.is_empty()
+    }
+
+    /// Drives the generator on `tick_interval` for as long as at least one session is
+    /// connected. Intended to be run on a dedicated thread for the lifetime of the
+    /// publisher. Takes `&self` (the generator sits behind its own `Mutex`) so `join`/
+    /// `poll`/`leave` remain callable from other threads while this loop is running.
+    pub fn run(&self) {
+        loop {
+            if self.evict_stale_sessions() {
+                match self.generator.lock().unwrap().generate_state() {
+                    Ok(state) => *self.current_state.lock().unwrap() = Some(state),
+                    Err(error) => log::warn!("Failed to generate radar state: {:#}", error),
+                }
+            }
+
+            std::thread::sleep(self.tick_interval);
+        }
+    }
+}
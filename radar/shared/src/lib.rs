@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+/// World-space position, as read directly off the game's scene node.
+pub type Vec3 = nalgebra::Vector3<f32>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarPlayerInfo {
+    pub controller_entity_id: u32,
+    pub pawn_entity_id: u32,
+
+    pub player_name: String,
+    pub player_flashtime: f32,
+    pub player_has_defuser: bool,
+    pub player_health: i32,
+
+    pub position: [f32; 3],
+    pub rotation: f32,
+
+    /// `CS2RadarGenerator` sets this to `Some` for every player it reports (only alive
+    /// pawns are reported at all). Lets the client dead-reckon between ticks: `position + velocity * (now - state_time)`.
+    pub velocity: Option<[f32; 3]>,
+
+    pub team_id: u8,
+    pub weapon: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarC4 {
+    pub entity_id: u32,
+    pub position: Vec3,
+    pub owner_entity_id: Option<u32>,
+}
+
+/// Whether a defuse is predicted to beat the bomb's fuse, derived by comparing the
+/// defuser's remaining time against the fuse's remaining time at the same reference
+/// tick. `margin` is in seconds and is zero or positive for `Success`/`Tight`, negative
+/// for `Fail`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum DefuseOutcome {
+    Success { margin: f32 },
+    Fail { margin: f32 },
+    Tight { margin: f32 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BombDefuser {
+    pub time_remaining: f32,
+    pub time_total: f32,
+
+    pub player_name: String,
+    pub defuse_outcome: DefuseOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PlantedC4State {
+    Active {
+        time_detonation: f32,
+        time_total: f32,
+
+        defuser: Option<BombDefuser>,
+    },
+    Defused {},
+    Detonated {},
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarPlantedC4 {
+    pub position: Vec3,
+    pub bomb_site: u8,
+    pub state: PlantedC4State,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum GrenadeKind {
+    Smoke,
+    Molotov,
+    Flashbang,
+    HeGrenade,
+    Decoy,
+    Inferno,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarGrenade {
+    pub entity_id: u32,
+    pub kind: GrenadeKind,
+    pub position: Vec3,
+    pub thrower_entity_id: Option<u32>,
+
+    /// Seconds remaining until the effect expires (smoke cloud clearing, inferno fire
+    /// burning out), recomputed every tick like `PlantedC4State::Active::time_detonation`.
+    /// `None` for a smoke that hasn't started its effect yet (still flying). Only `Smoke`
+    /// and `Inferno` ever carry a value; every other kind is always `None`.
+    pub effect_time: Option<f32>,
+
+    /// Active fire positions for `GrenadeKind::Inferno`. Empty for every other kind.
+    pub fire_positions: Vec<Vec3>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarState {
+    /// `Globals::time_2()` at the moment this state was captured, so clients can
+    /// dead-reckon player positions from `RadarPlayerInfo::velocity` between ticks.
+    pub state_time: f32,
+
+    pub players: Vec<RadarPlayerInfo>,
+    pub world_name: String,
+
+    pub planted_c4: Option<RadarPlantedC4>,
+    pub c4_entities: Vec<RadarC4>,
+    pub grenades: Vec<RadarGrenade>,
+}